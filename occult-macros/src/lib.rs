@@ -0,0 +1,237 @@
+//! # occult-macros
+//!
+//! The `Handler` blanket impl in `occult` is, by design, generic over almost everything: the
+//! core type, the state type, every argument's extractor, and the return type. That's exactly
+//! what makes it flexible, and exactly what makes the compiler's error when a function *isn't*
+//! a valid handler so hard to read: a missing `Extractor` impl three arguments deep surfaces as
+//! a wall of "the trait bound `Func: Handler<...>` is not satisfied".
+//!
+//! This crate adds `#[debug_handler]` (and its `#[occult::handler]` alias), which expands a
+//! handler function into itself plus a block of small, targeted assertions: one per argument,
+//! checking it implements `Extractor` for the core and state types you give the macro (or
+//! `FromInput`, for the last argument, which only needs to consume the input rather than clone
+//! it), and one for the return type, checking it satisfies `IntoHandlerOutput`. When a handler
+//! doesn't qualify, you get a compiler error pointing at the exact argument or return type
+//! responsible, instead of one pointing at the whole function.
+//!
+//! `#[debug_handler]` is a no-op at runtime: the assertions it adds are unit-returning
+//! functions that are never called, so they're optimized away entirely. Reach for it while
+//! you're debugging a handler that won't compile, the same way you'd reach for axum's
+//! `#[axum::debug_handler]`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use occult_macros::debug_handler;
+//!
+//! #[debug_handler(Frame)]
+//! async fn handler(topic: Topic) -> String {
+//!     format!("Hello, {topic}!")
+//! }
+//! ```
+//!
+//! If `Topic` doesn't implement `Extractor<Frame, _>`, the error points directly at `Topic`.
+//!
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, FnArg, ItemFn, Pat, ReturnType, Token, Type};
+
+/// The core and, optionally, state type a handler is checked against: `Frame` or
+/// `Frame, MyState`. State defaults to `()` when omitted, since most extractors are written
+/// generically over it.
+struct HandlerTypes {
+    core: Type,
+    state: Type,
+}
+
+impl Parse for HandlerTypes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let core = input.parse()?;
+
+        let state = if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            input.parse()?
+        } else {
+            syn::parse_quote!(())
+        };
+
+        Ok(HandlerTypes { core, state })
+    }
+}
+
+/// Expands a handler function into itself, plus compile-time assertions pinpointing exactly
+/// which argument or return type keeps it from qualifying as an `occult::Handler`. See the
+/// crate documentation for the full rationale.
+///
+/// Takes the core type the handler is invoked with, and optionally the state type (defaults
+/// to `()`): `#[debug_handler(Frame)]` or `#[debug_handler(Frame, MyState)]`.
+#[proc_macro_attribute]
+pub fn debug_handler(args: TokenStream, input: TokenStream) -> TokenStream {
+    expand(args, input)
+}
+
+/// Alias for [`debug_handler`], so it reads naturally as `#[occult::handler(Frame)]` when
+/// re-exported from the main crate.
+#[proc_macro_attribute]
+pub fn handler(args: TokenStream, input: TokenStream) -> TokenStream {
+    expand(args, input)
+}
+
+fn expand(args: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemFn);
+    let types = parse_macro_input!(args as HandlerTypes);
+
+    if let Some(receiver) = item.sig.inputs.iter().find_map(|arg| match arg {
+        FnArg::Receiver(receiver) => Some(receiver),
+        FnArg::Typed(_) => None,
+    }) {
+        let error = syn::Error::new(
+            receiver.span(),
+            "#[debug_handler] doesn't support functions that take `self`; extract it as a \
+             `State` argument instead",
+        )
+        .to_compile_error();
+
+        return quote! {
+            #item
+            #error
+        }
+        .into();
+    }
+
+    if !item.sig.generics.params.is_empty() {
+        let error = syn::Error::new(
+            item.sig.generics.span(),
+            "#[debug_handler] can't check a generic function, since the whole point is to \
+             pin its argument and return types down to something concrete",
+        )
+        .to_compile_error();
+
+        return quote! {
+            #item
+            #error
+        }
+        .into();
+    }
+
+    if item.sig.asyncness.is_none() {
+        let error = syn::Error::new(
+            item.sig.span(),
+            "#[debug_handler] only supports `async fn`; a closure can be checked by giving \
+             it a name and wrapping the body in an `async fn` first",
+        )
+        .to_compile_error();
+
+        return quote! {
+            #item
+            #error
+        }
+        .into();
+    }
+
+    let core = &types.core;
+    let state = &types.state;
+
+    // The handler macro only clones the input for the leading arguments; the last one is
+    // handed the original value and so only needs `FromInput`, not `Extractor`. Mirror that
+    // split here, or `#[debug_handler]` would falsely reject a valid `FromInput`-only
+    // terminal argument (e.g. one that owns a buffer it can't clone).
+    let typed_args: Vec<_> = item
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => Some(pat_type),
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+
+    let last_index = typed_args.len().checked_sub(1);
+
+    let argument_asserts = typed_args.iter().enumerate().map(|(index, pat_type)| {
+        let ty = &pat_type.ty;
+        let name = match &*pat_type.pat {
+            Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+            _ => "this argument".to_string(),
+        };
+        let is_terminal = Some(index) == last_index;
+
+        if is_terminal {
+            let message = format!(
+                "`{name}` isn't a FromInput<{core}, {state}> - see the `FromInput` (or \
+                 `Extractor`) impl it's missing",
+                core = quote!(#core),
+                state = quote!(#state),
+            );
+
+            quote_spanned! { ty.span() =>
+                const _: () = {
+                    fn assert_from_input<Arg, Core, State>()
+                    where
+                        Arg: ::occult::FromInput<Core, State>,
+                    {
+                    }
+
+                    #[doc = #message]
+                    fn __occult_debug_handler_argument() {
+                        assert_from_input::<#ty, #core, #state>();
+                    }
+                };
+            }
+        } else {
+            let message = format!(
+                "`{name}` isn't an Extractor<{core}, {state}> - see the `Extractor` impl it's \
+                 missing",
+                core = quote!(#core),
+                state = quote!(#state),
+            );
+
+            quote_spanned! { ty.span() =>
+                const _: () = {
+                    fn assert_extractor<Arg, Core, State>()
+                    where
+                        Arg: ::occult::Extractor<Core, State>,
+                    {
+                    }
+
+                    #[doc = #message]
+                    fn __occult_debug_handler_argument() {
+                        assert_extractor::<#ty, #core, #state>();
+                    }
+                };
+            }
+        }
+    });
+
+    let output_assert = match &item.sig.output {
+        ReturnType::Default => quote! {},
+        ReturnType::Type(_, ty) => quote_spanned! { ty.span() =>
+            const _: () = {
+                fn assert_into_handler_output<Output, Core, Marker>()
+                where
+                    Output: ::occult::IntoHandlerOutput<Core, Marker>,
+                {
+                }
+
+                /// If this line points you here, the handler's return type is neither
+                /// `Into<Core>` nor a `Result` whose `Ok` is `Into<Core>`.
+                fn __occult_debug_handler_output() {
+                    assert_into_handler_output::<#ty, #core, _>();
+                }
+            };
+        },
+    };
+
+    quote! {
+        #item
+
+        #(#argument_asserts)*
+
+        #output_assert
+    }
+    .into()
+}
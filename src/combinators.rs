@@ -0,0 +1,119 @@
+use crate::function_impl::BoxFuture;
+use crate::HandlerError;
+
+/// A handler that tries `left` first and falls back to `right` if, and only if, `left`
+/// failed because one of its arguments couldn't be extracted from the input. A failure
+/// raised by `left`'s own body still propagates instead of triggering the fallback.
+///
+/// Build one with [`OrExt::or`]: register several handlers keyed to the same core type and
+/// let the first one whose arguments successfully extract win.
+///
+/// # Example
+///
+/// ```rust
+/// use occult::{Extractor, Handler, OrExt};
+///
+/// #[derive(Clone)]
+/// struct Topic(String);
+///
+/// impl Extractor<String, ()> for Topic {
+///     type Error = String;
+///     type Config = ();
+///
+///     fn extract<Context>(topic: String, _: &Context, _: &Self::Config) -> Result<Self, Self::Error>
+///     where
+///         Self: Sized,
+///         Context: Into<()> + Clone,
+///     {
+///         topic
+///             .strip_prefix("topic:")
+///             .map(|topic| Topic(topic.to_string()))
+///             .ok_or_else(|| "missing topic prefix".to_string())
+///     }
+/// }
+///
+/// async fn named_handler(Topic(topic): Topic) -> String {
+///     format!("Hello, {topic}!")
+/// }
+///
+/// async fn fallback_handler() -> String {
+///     "Hello, stranger!".to_string()
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let handler = named_handler.or(fallback_handler);
+///
+///     let response = handler.invoke("world".to_string(), ()).await.unwrap();
+///     assert_eq!(response, "Hello, stranger!");
+///
+///     let response = handler.invoke("topic:world".to_string(), ()).await.unwrap();
+///     assert_eq!(response, "Hello, world!");
+/// }
+/// ```
+///
+#[derive(Clone)]
+pub struct Or<L, R> {
+    left: L,
+    right: R,
+}
+
+/// Extension trait providing the `.or()` combinator used to build an [`Or`] handler.
+pub trait OrExt<T, Args, State, Marker>: crate::Handler<T, Args, State, Marker> + Sized {
+    /// Fall back to `right` if `self` rejects because one of its arguments failed to
+    /// extract from the input.
+    fn or<R, RightArgs, RightMarker>(self, right: R) -> Or<Self, R>
+    where
+        R: crate::Handler<T, RightArgs, State, RightMarker>,
+    {
+        Or {
+            left: self,
+            right,
+        }
+    }
+}
+
+impl<T, Args, State, Marker, H> OrExt<T, Args, State, Marker> for H where
+    H: crate::Handler<T, Args, State, Marker>
+{
+}
+
+impl<T, LeftArgs, RightArgs, State, LeftMarker, RightMarker, L, R>
+    crate::Handler<T, (LeftArgs, RightArgs), State, (LeftMarker, RightMarker)> for Or<L, R>
+where
+    T: Clone + Send + 'static,
+    State: Clone + Send + 'static,
+    L: crate::Handler<T, LeftArgs, State, LeftMarker, Error = HandlerError> + Send + 'static,
+    L::Future: Send,
+    L::Response: Send,
+    R: crate::Handler<
+            T,
+            RightArgs,
+            State,
+            RightMarker,
+            Error = HandlerError,
+            Response = L::Response,
+        > + Send
+        + 'static,
+    R::Future: Send,
+{
+    type Response = L::Response;
+    type Error = HandlerError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn invoke(&self, input: impl Into<T>, state: State) -> Self::Future {
+        let left = self.left.clone();
+        let right = self.right.clone();
+        let input = input.into();
+
+        Box::pin(async move {
+            match left.invoke(input.clone(), state.clone()).await {
+                Ok(response) => return Ok(response),
+                Err(HandlerError::ExtractionRejected(_)) => {}
+                Err(error @ HandlerError::Handler(_)) => return Err(error),
+            }
+
+            right.invoke(input, state).await
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A typed store for per-extractor [`Extractor::Config`](crate::Extractor::Config) values,
+/// keyed by the config's own type.
+///
+/// This gives library consumers a first-class place to put extractor knobs (a max length, an
+/// encoding, a timeout) without smuggling them through `State`. Looking up a type that hasn't
+/// been registered falls back to `Config::default()`, so registering configuration is
+/// opt-in: extractors that use `type Config = ()` never need a registered entry.
+///
+/// # Example
+///
+/// ```rust
+/// use occult::ConfigStore;
+///
+/// #[derive(Default, Clone)]
+/// struct MaxLength(usize);
+///
+/// let mut store = ConfigStore::new();
+/// store.insert(MaxLength(128));
+///
+/// assert_eq!(store.get::<MaxLength>().0, 128);
+/// assert_eq!(store.get::<()>(), ());
+/// ```
+///
+#[derive(Clone, Default)]
+pub struct ConfigStore {
+    configs: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl ConfigStore {
+    /// Create an empty configuration store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a configuration value, keyed by its own type.
+    pub fn insert<Config>(&mut self, config: Config) -> &mut Self
+    where
+        Config: Clone + Send + Sync + 'static,
+    {
+        self.configs.insert(TypeId::of::<Config>(), Arc::new(config));
+        self
+    }
+
+    /// Look up a configuration value, falling back to `Config::default()` if none was
+    /// registered.
+    pub fn get<Config>(&self) -> Config
+    where
+        Config: Default + Clone + Send + Sync + 'static,
+    {
+        self.configs
+            .get(&TypeId::of::<Config>())
+            .and_then(|config| config.downcast_ref::<Config>())
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// Supplies a [`ConfigStore`] alongside a handler's `State`.
+///
+/// Implement this for your state type to register extractor configuration, overriding
+/// [`config_store`](Self::config_store) to return the store you built up; the default method
+/// body hands back an empty store, so states with nothing to configure can `impl
+/// HasConfigStore for MyState {}` and stop there.
+///
+/// There's deliberately no blanket implementation: a blanket `impl<State> HasConfigStore for
+/// State` would make every concrete `impl HasConfigStore for MyState` a conflicting
+/// implementation, which defeats the whole point of letting a state type override it.
+pub trait HasConfigStore {
+    /// The configuration store made available to extractors during this handler invocation.
+    fn config_store(&self) -> ConfigStore {
+        ConfigStore::default()
+    }
+}
+
+impl HasConfigStore for () {}
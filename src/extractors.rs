@@ -32,8 +32,9 @@ where
     GivenState: Clone,
 {
     type Error = String;
+    type Config = ();
 
-    fn extract<Context>(_: T, context: &Context) -> Result<Self, Self::Error>
+    fn extract<Context>(_: T, context: &Context, _: &Self::Config) -> Result<Self, Self::Error>
     where
         Self: Sized,
         Context: Into<GivenState> + Clone,
@@ -43,3 +44,229 @@ where
         Ok(State(context.clone()))
     }
 }
+
+/// An extractor that makes another extractor optional. If the inner extractor rejects,
+/// `Optional` reports `None` instead of failing the handler invocation, so a single
+/// argument being absent doesn't take down the whole call.
+///
+/// # Example
+///
+/// ```rust
+/// use occult::{Extractor, Handler, Optional};
+///
+/// #[derive(Clone)]
+/// struct Topic(String);
+///
+/// impl<State> Extractor<String, State> for Topic {
+///     type Error = String;
+///     type Config = ();
+///
+///     fn extract<Context>(topic: String, _: &Context, _: &Self::Config) -> Result<Self, Self::Error>
+///     where
+///         Self: Sized,
+///         Context: Into<State> + Clone,
+///     {
+///         Ok(Topic(topic))
+///     }
+/// }
+///
+/// async fn handler(Optional(maybe_topic): Optional<Topic>) -> String {
+///     match maybe_topic {
+///         Some(topic) => format!("Hello, {}!", topic.0),
+///         None => "Hello, stranger!".to_string(),
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let response = handler.invoke("world".to_string(), ()).await.unwrap();
+///     assert_eq!(response, "Hello, world!");
+/// }
+/// ```
+///
+pub struct Optional<E>(pub Option<E>);
+
+impl<E, T, GivenState> crate::Extractor<T, GivenState> for Optional<E>
+where
+    E: crate::Extractor<T, GivenState>,
+    T: Clone,
+{
+    type Error = std::convert::Infallible;
+    type Config = E::Config;
+
+    fn extract<Context>(
+        topic: T,
+        context: &Context,
+        config: &Self::Config,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Context: Into<GivenState> + Clone,
+    {
+        Ok(Optional(E::extract(topic, context, config).ok()))
+    }
+}
+
+/// An extractor that captures another extractor's rejection in-band instead of short
+/// circuiting the whole handler invocation. Where [`Optional`] discards the rejection,
+/// `Fallible` hands it to the handler body so it can decide what to do with it.
+///
+/// # Example
+///
+/// ```rust
+/// use occult::{Extractor, Handler, Fallible};
+///
+/// #[derive(Clone)]
+/// struct Topic(String);
+///
+/// impl<State> Extractor<String, State> for Topic {
+///     type Error = String;
+///     type Config = ();
+///
+///     fn extract<Context>(topic: String, _: &Context, _: &Self::Config) -> Result<Self, Self::Error>
+///     where
+///         Self: Sized,
+///         Context: Into<State> + Clone,
+///     {
+///         Ok(Topic(topic))
+///     }
+/// }
+///
+/// async fn handler(Fallible(topic): Fallible<Topic, String, ()>) -> String {
+///     match topic {
+///         Ok(topic) => format!("Hello, {}!", topic.0),
+///         Err(rejection) => format!("Couldn't extract a topic: {rejection}"),
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let response = handler.invoke("world".to_string(), ()).await.unwrap();
+///     assert_eq!(response, "Hello, world!");
+/// }
+/// ```
+///
+pub struct Fallible<E, T, GivenState>(pub Result<E, E::Error>)
+where
+    E: crate::Extractor<T, GivenState>;
+
+impl<E, T, GivenState> crate::Extractor<T, GivenState> for Fallible<E, T, GivenState>
+where
+    E: crate::Extractor<T, GivenState>,
+    T: Clone,
+{
+    type Error = std::convert::Infallible;
+    type Config = E::Config;
+
+    fn extract<Context>(
+        topic: T,
+        context: &Context,
+        config: &Self::Config,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Context: Into<GivenState> + Clone,
+    {
+        Ok(Fallible(E::extract(topic, context, config)))
+    }
+}
+
+/// An extractor that tries two interchangeable parse strategies against the same input,
+/// favoring `L` and falling back to `R` if `L` rejects. This lets a handler argument accept
+/// one of two shapes (say, a JSON topic or a plain-string topic) without writing a bespoke
+/// extractor that understands both.
+///
+/// # Example
+///
+/// ```rust
+/// use occult::{Either, Extractor, Handler};
+///
+/// #[derive(Clone)]
+/// struct JsonTopic(String);
+///
+/// impl<State> Extractor<String, State> for JsonTopic {
+///     type Error = String;
+///     type Config = ();
+///
+///     fn extract<Context>(topic: String, _: &Context, _: &Self::Config) -> Result<Self, Self::Error>
+///     where
+///         Self: Sized,
+///         Context: Into<State> + Clone,
+///     {
+///         topic
+///             .strip_prefix("json:")
+///             .map(|topic| JsonTopic(topic.to_string()))
+///             .ok_or_else(|| "not a json topic".to_string())
+///     }
+/// }
+///
+/// #[derive(Clone)]
+/// struct PlainTopic(String);
+///
+/// impl<State> Extractor<String, State> for PlainTopic {
+///     type Error = String;
+///     type Config = ();
+///
+///     fn extract<Context>(topic: String, _: &Context, _: &Self::Config) -> Result<Self, Self::Error>
+///     where
+///         Self: Sized,
+///         Context: Into<State> + Clone,
+///     {
+///         Ok(PlainTopic(topic))
+///     }
+/// }
+///
+/// async fn handler(topic: Either<JsonTopic, PlainTopic>) -> String {
+///     match topic {
+///         Either::Left(JsonTopic(topic)) => format!("json topic: {topic}"),
+///         Either::Right(PlainTopic(topic)) => format!("plain topic: {topic}"),
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let response = handler.invoke("world".to_string(), ()).await.unwrap();
+///     assert_eq!(response, "plain topic: world");
+/// }
+/// ```
+///
+pub enum Either<L, R> {
+    /// The left extractor succeeded.
+    Left(L),
+    /// The left extractor rejected, but the right extractor succeeded.
+    Right(R),
+}
+
+impl<L, R, T, GivenState> crate::Extractor<T, GivenState> for Either<L, R>
+where
+    L: crate::Extractor<T, GivenState>,
+    R: crate::Extractor<T, GivenState>,
+    L::Error: std::fmt::Display,
+    R::Error: std::fmt::Display,
+    T: Clone,
+{
+    type Error = String;
+    type Config = (L::Config, R::Config);
+
+    fn extract<Context>(
+        topic: T,
+        context: &Context,
+        config: &Self::Config,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Context: Into<GivenState> + Clone,
+    {
+        let (left_config, right_config) = config;
+
+        match L::extract(topic.clone(), context, left_config) {
+            Ok(left) => Ok(Either::Left(left)),
+            Err(left_rejection) => match R::extract(topic, context, right_config) {
+                Ok(right) => Ok(Either::Right(right)),
+                Err(right_rejection) => Err(format!(
+                    "neither extractor matched: {left_rejection}, {right_rejection}"
+                )),
+            },
+        }
+    }
+}
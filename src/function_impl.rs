@@ -1,60 +1,181 @@
 use std::{future::Future, pin::Pin};
 
-type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+pub(crate) type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
-macro_rules! define_handler_for_tuple ({ $($param:ident)* } => {
+/// The error produced by the blanket [`Handler`](crate::Handler) implementation.
+///
+/// An extractor rejecting an argument and the handler body itself failing are different
+/// situations for a caller that wants to retry or fall back, so the blanket impl keeps them
+/// apart instead of flattening both into a single boxed error. [`Or`](crate::Or) relies on
+/// this distinction: it only falls back to its second handler when the first one's error is
+/// `ExtractionRejected`.
+#[derive(Debug)]
+pub enum HandlerError {
+    /// One of the handler's arguments failed to extract from the input.
+    ExtractionRejected(Box<dyn std::error::Error>),
+    /// The handler body itself returned an error.
+    Handler(Box<dyn std::error::Error>),
+}
+
+impl std::fmt::Display for HandlerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HandlerError::ExtractionRejected(error) => write!(f, "extractor rejected: {error}"),
+            HandlerError::Handler(error) => write!(f, "handler failed: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for HandlerError {}
+
+mod sealed {
+    pub trait Sealed<T, Marker> {}
+}
+
+/// Marker for [`IntoHandlerOutput`]'s infallible-value case: `Output: Into<T>`.
+#[doc(hidden)]
+pub struct ValueOutput;
+
+/// Marker for [`IntoHandlerOutput`]'s `Result` case: `Result<Response, Error>`.
+#[doc(hidden)]
+pub struct ResultOutput;
+
+/// Folds a handler body's return value into the response the blanket
+/// [`Handler`](crate::Handler) impl produces.
+///
+/// A handler can either return its response directly, or return a `Result` so application
+/// logic can fail too. This trait is sealed: its only two implementations are the ones
+/// below, distinguished by the `Marker` type parameter so they don't overlap (`Output: Into<T>`
+/// and `Result<Response, Error>` would otherwise both apply to a `Result`-returning handler,
+/// which is a conflicting-impls error). `define_handler_for_tuple` leaves `Marker` for the
+/// compiler to infer, so it can call
+/// [`into_handler_output`](IntoHandlerOutput::into_handler_output) without caring which one
+/// a given handler used.
+pub trait IntoHandlerOutput<T, Marker>: sealed::Sealed<T, Marker> {
+    /// Fold `self` into the handler's response, turning a body failure into a
+    /// [`HandlerError::Handler`].
+    fn into_handler_output(self) -> Result<T, HandlerError>;
+}
+
+impl<T, Output> sealed::Sealed<T, ValueOutput> for Output where Output: Into<T> {}
+
+impl<T, Output> IntoHandlerOutput<T, ValueOutput> for Output
+where
+    Output: Into<T>,
+{
+    fn into_handler_output(self) -> Result<T, HandlerError> {
+        Ok(self.into())
+    }
+}
+
+impl<T, Response, Error> sealed::Sealed<T, ResultOutput> for Result<Response, Error>
+where
+    Response: Into<T>,
+    Error: Into<Box<dyn std::error::Error>>,
+{
+}
+
+impl<T, Response, Error> IntoHandlerOutput<T, ResultOutput> for Result<Response, Error>
+where
+    Response: Into<T>,
+    Error: Into<Box<dyn std::error::Error>>,
+{
+    fn into_handler_output(self) -> Result<T, HandlerError> {
+        self.map(Into::into)
+            .map_err(|error| HandlerError::Handler(error.into()))
+    }
+}
+
+macro_rules! define_handler_for_tuple {
+    (@nullary) => {
+        #[allow(non_snake_case, unused_mut, unused_variables)]
+        impl<T, Func, Future, State, Output, Marker> crate::Handler<T, (), State, Marker> for Func
+        where
+            T: Clone + Send + 'static,
+            Func: FnOnce() -> Future + Clone + Send + 'static,
+            State: Clone + Send + Sync + 'static,
+            Output: IntoHandlerOutput<T, Marker>,
+            Future: std::future::Future<Output = Output> + Send + 'static,
+        {
+            type Response = T;
+            type Error = HandlerError;
+            type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+            fn invoke(&self, input: impl Into<T>, state: State) -> Self::Future {
+                let handler = self.clone();
+                let _ = input.into();
+                let _ = state;
+
+                Box::pin(async move {
+                    let handler = handler;
+                    let response = handler().await;
+
+                    response.into_handler_output()
+                })
+            }
+        }
+    };
+    ($($param:ident)* ; $last:ident) => {
 #[allow(non_snake_case, unused_mut, unused_variables)]
-impl<T, Func, Future, State, Output, $($param,)*>
-    crate::Handler<T, ($($param,)*), State> for Func
+impl<T, Func, Future, State, Output, Marker, $($param,)* $last>
+    crate::Handler<T, ($($param,)* $last,), State, Marker> for Func
 where
     T: Clone + Send + 'static,
-    Func: FnOnce($($param,)*) -> Future + Clone + Send + 'static,
-    State: Clone + Send + Sync + 'static,
-    Output: Into<T>,
+    Func: FnOnce($($param,)* $last) -> Future + Clone + Send + 'static,
+    State: Clone + Send + Sync + crate::HasConfigStore + 'static,
+    Output: IntoHandlerOutput<T, Marker>,
     Future: std::future::Future<Output = Output> + Send + 'static,
     $(
         $param: crate::Extractor<T, State> + Send,
         $param::Error: Into<Box<dyn std::error::Error>>,
     )*
+    $last: crate::FromInput<T, State> + Send,
+    $last::Error: Into<Box<dyn std::error::Error>>,
 {
     type Response = T;
-    type Error = Box<dyn std::error::Error>;
+    type Error = HandlerError;
     type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
 
     fn invoke(&self, input: impl Into<T>, state: State) -> Self::Future {
         let handler = self.clone();
         let input = input.into();
+        let config_store = crate::HasConfigStore::config_store(&state);
 
         Box::pin(async move {
-            let input = &input;
             let handler = handler;
             let context = &state;
 
             $(
-                let $param = match $param::extract(input.clone(), context) {
+                let $param = match $param::extract(input.clone(), context, &config_store.get::<$param::Config>()) {
                     Ok(value) => value,
-                    Err(rejection) => return Err(rejection.into()),
+                    Err(rejection) => return Err(HandlerError::ExtractionRejected(rejection.into())),
                 };
             )*
 
-            let response = handler($($param,)*).await;
+            let $last = match $last::from_input(input, context, &config_store.get::<$last::Config>()) {
+                Ok(value) => value,
+                Err(rejection) => return Err(HandlerError::ExtractionRejected(rejection.into())),
+            };
+
+            let response = handler($($param,)* $last).await;
 
-            Ok(response.into())
+            response.into_handler_output()
         })
     }
 }
-});
-
-define_handler_for_tuple! {}
-define_handler_for_tuple! { A }
-define_handler_for_tuple! { A B }
-define_handler_for_tuple! { A B C }
-define_handler_for_tuple! { A B C D }
-define_handler_for_tuple! { A B C D E }
-define_handler_for_tuple! { A B C D E F }
-define_handler_for_tuple! { A B C D E F G }
-define_handler_for_tuple! { A B C D E F G H }
-define_handler_for_tuple! { A B C D E F G H I }
-define_handler_for_tuple! { A B C D E F G H I J }
-define_handler_for_tuple! { A B C D E F G H I J K }
-define_handler_for_tuple! { A B C D E F G H I J K L }
+    };
+}
+
+define_handler_for_tuple! { @nullary }
+define_handler_for_tuple! { ; A }
+define_handler_for_tuple! { A ; B }
+define_handler_for_tuple! { A B ; C }
+define_handler_for_tuple! { A B C ; D }
+define_handler_for_tuple! { A B C D ; E }
+define_handler_for_tuple! { A B C D E ; F }
+define_handler_for_tuple! { A B C D E F ; G }
+define_handler_for_tuple! { A B C D E F G ; H }
+define_handler_for_tuple! { A B C D E F G H ; I }
+define_handler_for_tuple! { A B C D E F G H I ; J }
+define_handler_for_tuple! { A B C D E F G H I J ; K }
+define_handler_for_tuple! { A B C D E F G H I J K ; L }
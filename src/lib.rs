@@ -27,8 +27,22 @@
 //! If you have a ton of people doing work on different layers, it might be ideal. Otherwise,
 //! it might add too much extra time to debugging work!
 //!
+mod combinators;
+mod config;
+mod extractors;
 mod function_impl;
 
+pub use combinators::{Or, OrExt};
+pub use config::{ConfigStore, HasConfigStore};
+pub use extractors::{Either, Fallible, Optional, State};
+pub use function_impl::{HandlerError, IntoHandlerOutput};
+
+/// Re-exports [`occult_macros::debug_handler`] (and its `handler` alias) under the
+/// `macros` feature, so a decipherable handler error is one `#[occult::handler(...)]` away
+/// without adding `occult-macros` as a direct dependency.
+#[cfg(feature = "macros")]
+pub use occult_macros::{debug_handler, handler};
+
 use std::future::Future;
 
 /// # Extractor
@@ -64,14 +78,94 @@ use std::future::Future;
 /// the handler. This isn't a one to one link in the case of the error type: anything that can
 /// understand extractor errors and how to cast from them using `From`/`Into` can be used.
 ///
+/// ## Config Type
+///
+/// The config type lets an extractor be parameterized beyond `State`, for knobs that belong
+/// to the extractor itself rather than the application (a max length, an encoding). It's
+/// looked up in the [`ConfigStore`] carried by `State` (see [`HasConfigStore`]), falling back
+/// to `Config::default()` when nothing is registered. Extractors that don't need configuring
+/// can simply set `type Config = ();`.
+///
 pub trait Extractor<T, State> {
     /// The error type that the extractor can return.
     type Error;
 
-    /// Extract the input type from the input value and the given state context.
-    fn extract(topic: T, context: &impl Into<State>) -> Result<Self, Self::Error>
+    /// The configuration type that this extractor reads from the [`ConfigStore`]. Set this
+    /// to `()` if the extractor has no configuration.
+    type Config: Default + Clone + Send + Sync + 'static;
+
+    /// Extract the input type from the input value, the given state context, and this
+    /// extractor's configuration.
+    ///
+    /// `Context` is generic rather than `Self`'s own `State` directly, so an extractor can
+    /// be handed anything convertible into its state, not just the exact type a handler was
+    /// invoked with. It's bound `Clone` because the context is only ever borrowed here:
+    /// extractors that need to own a `State` (see [`State`](crate::State)) have to clone
+    /// their way to one.
+    fn extract<Context>(
+        topic: T,
+        context: &Context,
+        config: &Self::Config,
+    ) -> Result<Self, Self::Error>
     where
-        Self: Sized;
+        Self: Sized,
+        Context: Into<State> + Clone;
+}
+
+/// # FromInput
+///
+/// A handler gives each of its leading arguments its own clone of the input, because
+/// [`Extractor::extract`] takes the input by value. That's fine for cheap-to-clone core types, but it rules
+/// out extractors that need to own the whole input outright, such as streaming decoders or
+/// zero-copy parsers over a large buffer.
+///
+/// `FromInput` fills that gap for the *last* argument of a handler, the one that still holds
+/// the original, un-cloned input by the time the handler macro gets to it. Every
+/// [`Extractor`] already implements `FromInput` for free, so existing extractors keep
+/// working unchanged in the terminal position, they simply stop paying for a clone they
+/// didn't need. Implement `FromInput` directly, instead of `Extractor`, when your type must
+/// consume the whole input value. A direct implementation has to name a concrete `State`
+/// type: a generic `impl<State> FromInput<T, State>` conflicts with the blanket
+/// implementation for extractors.
+///
+pub trait FromInput<T, State> {
+    /// The error type that the extractor can return.
+    type Error;
+
+    /// The configuration type that this extractor reads from the [`ConfigStore`]. Set this
+    /// to `()` if the extractor has no configuration.
+    type Config: Default + Clone + Send + Sync + 'static;
+
+    /// Consume the input value, the given state context, and this extractor's configuration
+    /// to produce `Self`.
+    fn from_input<Context>(
+        input: T,
+        context: &Context,
+        config: &Self::Config,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Context: Into<State> + Clone;
+}
+
+impl<E, T, State> FromInput<T, State> for E
+where
+    E: Extractor<T, State>,
+{
+    type Error = E::Error;
+    type Config = E::Config;
+
+    fn from_input<Context>(
+        input: T,
+        context: &Context,
+        config: &Self::Config,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Context: Into<State> + Clone,
+    {
+        E::extract(input, context, config)
+    }
 }
 
 /// # Handler
@@ -85,7 +179,15 @@ pub trait Extractor<T, State> {
 /// - Casting traits for error types.
 /// - A function that takes the input types and returns a future.
 ///
-pub trait Handler<T, Args, State>: Clone {
+/// ## Marker
+///
+/// `Marker` has no bearing on what a handler does; it exists purely so the blanket impl for
+/// a bare-value-returning function and the one for a `Result`-returning function don't
+/// conflict (see [`IntoHandlerOutput`](crate::IntoHandlerOutput)'s own `Marker`, which this
+/// one mirrors). It's left generic here, rather than defaulted, so callers that only care
+/// that *some* handler was given (e.g. [`OrExt`](crate::OrExt)) can stay generic over it too.
+///
+pub trait Handler<T, Args, State, Marker>: Clone {
     /// The response type that the handler will return.
     type Response: Into<T>;
     /// The error type that the handler will return.
@@ -40,10 +40,16 @@ impl std::fmt::Display for Topic {
 // it stays generic.
 impl<State> Extractor<Frame, State> for Topic {
     type Error = String;
+    type Config = ();
 
-    fn extract<Context>(topic: Frame, _: &Context) -> Result<Self, Self::Error>
+    fn extract<Context>(
+        topic: Frame,
+        _: &Context,
+        _: &Self::Config,
+    ) -> Result<Self, Self::Error>
     where
         Self: Sized,
+        Context: Into<State> + Clone,
     {
         let topic = String::from_utf8(topic.0).map_err(|err| err.to_string())?;
         Ok(Topic(topic))
@@ -141,10 +147,10 @@ async fn narrowing_types() -> Result<(), Box<dyn std::error::Error>> {
     // Usually the only arguments you won't know ahead of time are the Args and State types.
     // So let's define everything else we need: the handler type, the input type, and the
     // output type.
-    async fn handle_the_handler<Args, State>(
-        handler: impl Handler<Frame, Args, State, Error = Box<dyn std::error::Error>>,
+    async fn handle_the_handler<Args, State, Marker>(
+        handler: impl Handler<Frame, Args, State, Marker, Error = occult::HandlerError>,
         state: State,
-    ) -> Result<Frame, Box<dyn std::error::Error>> {
+    ) -> Result<Frame, occult::HandlerError> {
         match handler.invoke(Frame(b"world".to_vec()), state).await {
             Ok(frame) => Ok(frame.into()),
             Err(err) => Err(err),
@@ -152,10 +158,10 @@ async fn narrowing_types() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // We can be more terse!
-    async fn tersely_handle_the_handler<Args, State>(
-        handler: impl Handler<Frame, Args, State, Error = Box<dyn std::error::Error>>,
+    async fn tersely_handle_the_handler<Args, State, Marker>(
+        handler: impl Handler<Frame, Args, State, Marker, Error = occult::HandlerError>,
         state: State,
-    ) -> Result<Frame, Box<dyn std::error::Error>> {
+    ) -> Result<Frame, occult::HandlerError> {
         handler
             .invoke(Frame(b"world".to_vec()), state)
             .await
@@ -184,10 +190,10 @@ async fn narrowing_types() -> Result<(), Box<dyn std::error::Error>> {
 async fn narrowing_types_closures() -> Result<(), Box<dyn std::error::Error>> {
     let handler = |topic: Topic| async move { format!("Hello, {topic}!") };
 
-    async fn tersely_handle_the_handler<Args, State>(
-        handler: impl Handler<Frame, Args, State, Error = Box<dyn std::error::Error>>,
+    async fn tersely_handle_the_handler<Args, State, Marker>(
+        handler: impl Handler<Frame, Args, State, Marker, Error = occult::HandlerError>,
         state: State,
-    ) -> Result<Frame, Box<dyn std::error::Error>> {
+    ) -> Result<Frame, occult::HandlerError> {
         handler
             .invoke(Frame(b"world".to_vec()), state)
             .await
@@ -219,6 +225,8 @@ async fn extracting_from_state() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    impl occult::HasConfigStore for ArbitraryState {}
+
     async fn handler(topic: Topic, State(state): State<ArbitraryState>) -> String {
         format!(
             "Hello, {topic} - {how_arbitrary}!",
@@ -235,3 +243,138 @@ async fn extracting_from_state() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn fallible_handler_body() -> Result<(), Box<dyn std::error::Error>> {
+    // A handler isn't limited to extraction failures: its body can fail too, by returning
+    // a `Result` whose error can be turned into a boxed error.
+    async fn handler(topic: Topic) -> Result<String, String> {
+        if topic.0 == "world" {
+            Ok(format!("Hello, {topic}!"))
+        } else {
+            Err(format!("don't know how to greet {topic}"))
+        }
+    }
+
+    assert_eq!(
+        handler.invoke(Frame(b"world".to_vec()), ()).await?,
+        Frame(b"Hello, world!".to_vec())
+    );
+
+    let error = handler
+        .invoke(Frame(b"moon".to_vec()), ())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, occult::HandlerError::Handler(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn terminal_argument_owns_the_input() -> Result<(), Box<dyn std::error::Error>> {
+    // The last argument of a handler is handed the input by value instead of a clone,
+    // so it can own the whole buffer. Here `OwnedFrame` only implements `FromInput`, not
+    // `Extractor`, so it can't be used in any position but the last.
+    use occult::FromInput;
+
+    struct OwnedFrame(Vec<u8>);
+
+    impl FromInput<Frame, ()> for OwnedFrame {
+        type Error = std::convert::Infallible;
+        type Config = ();
+
+        fn from_input<Context>(
+            frame: Frame,
+            _: &Context,
+            _: &Self::Config,
+        ) -> Result<Self, Self::Error>
+        where
+            Self: Sized,
+            Context: Into<()> + Clone,
+        {
+            Ok(OwnedFrame(frame.0))
+        }
+    }
+
+    async fn handler(topic: Topic, owned: OwnedFrame) -> String {
+        format!("Hello, {topic}! ({} bytes)", owned.0.len())
+    }
+
+    assert_eq!(
+        handler.invoke(Frame(b"world".to_vec()), ()).await?,
+        Frame(b"Hello, world! (5 bytes)".to_vec())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn extractor_config() -> Result<(), Box<dyn std::error::Error>> {
+    // Extractors can be parameterized with an associated `Config` type, looked up from a
+    // `ConfigStore` carried alongside `State`. An extractor whose config isn't registered
+    // just gets `Config::default()`.
+    use occult::{ConfigStore, HasConfigStore};
+
+    #[derive(Clone, Default)]
+    struct MaxLength(usize);
+
+    struct LimitedTopic(String);
+
+    impl<State> Extractor<Frame, State> for LimitedTopic {
+        type Error = String;
+        type Config = MaxLength;
+
+        fn extract<Context>(
+            topic: Frame,
+            _: &Context,
+            MaxLength(max_length): &Self::Config,
+        ) -> Result<Self, Self::Error>
+        where
+            Self: Sized,
+            Context: Into<State> + Clone,
+        {
+            let topic = String::from_utf8(topic.0).map_err(|err| err.to_string())?;
+
+            if *max_length != 0 && topic.len() > *max_length {
+                return Err(format!("topic longer than {max_length} bytes"));
+            }
+
+            Ok(LimitedTopic(topic))
+        }
+    }
+
+    #[derive(Clone)]
+    struct AppState(ConfigStore);
+
+    impl HasConfigStore for AppState {
+        fn config_store(&self) -> ConfigStore {
+            self.0.clone()
+        }
+    }
+
+    async fn handler(LimitedTopic(topic): LimitedTopic) -> String {
+        format!("Hello, {topic}!")
+    }
+
+    let mut config = ConfigStore::new();
+    config.insert(MaxLength(3));
+
+    assert_eq!(
+        handler
+            .invoke(Frame(b"world".to_vec()), AppState(config))
+            .await
+            .unwrap_err()
+            .to_string(),
+        "extractor rejected: topic longer than 3 bytes".to_string()
+    );
+
+    assert_eq!(
+        handler
+            .invoke(Frame(b"world".to_vec()), AppState(ConfigStore::new()))
+            .await?,
+        Frame(b"Hello, world!".to_vec())
+    );
+
+    Ok(())
+}